@@ -9,7 +9,7 @@ use std::{
     time::Duration,
 };
 
-use cli_progress::{CLIDisplayManager, CLIDisplayNodeType, erasing_println};
+use cli_progress::{CLIDisplayManager, CLIDisplayNodeType, ProgressBarStyle, ProgressIterator, erasing_println};
 use rand::{Rng, rng};
 
 fn main() {
@@ -22,10 +22,17 @@ fn main() {
     // The progress bars are stores as Arc<AtomicU8>
     let bars: [Arc<AtomicU8>; 3] = array::from_fn(|_| Arc::new(AtomicU8::new(0)));
 
+    // Each bar is fed by a `Progress` adapter instead of manual `fetch_add` calls
+    let mut iters = array::from_fn::<_, 3, _>(|i| (0..100u8).progress_with(bars[i].clone()));
+    let mut completed = [false; 3];
+
     // The progress bars are initialized in the CLIDisplayManager
     clidm.modify(|modify| {
         for bar in &bars {
-            modify.push(CLIDisplayNodeType::ProgressBar(bar.clone()));
+            modify.push(CLIDisplayNodeType::ProgressBar(
+                bar.clone(),
+                ProgressBarStyle::Coarse,
+            ));
         }
     });
 
@@ -42,7 +49,11 @@ fn main() {
             }
         }
 
-        if bars[rand].fetch_add(1, Ordering::Relaxed) == 99 {
+        iters[rand].next();
+
+        if !completed[rand] && bars[rand].load(Ordering::Relaxed) == 100 {
+            completed[rand] = true;
+
             clidm.modify(|modify| {
                 // Notice that this is possible
                 erasing_println!(modify, "Bar {} completed!", rand);
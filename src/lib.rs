@@ -6,11 +6,12 @@
 //! While the [CLIDisplayManager] is in use, no other [CLIDisplayManager] should be active,
 //! however stdout can still be used through the [erasing_println] macro during [modify](CLIDisplayManager::modify) calls and it will appear in front of the displayed progress/process.
 //!
-//! Currently there are three types of displays:
+//! Currently there are four types of displays:
 //!
 //! - [Just text](CLIDisplayNodeType::Message)
 //! - [Text with a progress spinner at the end](CLIDisplayNodeType::SpinningMessage)
 //! - [A progress bar whose progress can be set through an `Arc<AtomicU8>`](CLIDisplayNodeType::ProgressBar)
+//! - [A transfer-style bar showing a smoothed throughput and ETA](CLIDisplayNodeType::RateProgress)
 //!
 //! Example with progress bars:
 //! `cargo run --example progress`
@@ -24,22 +25,82 @@ use std::{
     borrow::Cow,
     io::{Write, stdout},
     mem::forget,
-    ops::Neg,
     sync::{
-        Arc, Condvar, Mutex, RwLock,
+        Arc, Mutex,
         atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering::*},
+        mpsc::{self, Sender},
     },
     thread::{Builder, JoinHandle},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
+use terminal_size::{Width, terminal_size};
+
 const CURSOR_HIDE: &str = "\x1B[?25l";
 const CURSOR_SHOW: &str = "\x1B[?25h";
 const ERASE_LINE: &str = "\x1b[2K\r";
 const CURSOR_UP: &str = "\x1b[1A";
 
-#[doc(hidden)]
-pub const _ERASE_LINE: &str = ERASE_LINE;
+/// Terminal width assumed when the real width can't be determined
+const DEFAULT_TERM_WIDTH: usize = 80;
+const MIN_BAR_WIDTH: usize = 10;
+const MAX_BAR_WIDTH: usize = 40;
+
+/// Queries the TTY's current column count, falling back to [DEFAULT_TERM_WIDTH]
+fn query_terminal_width() -> usize {
+    terminal_size()
+        .map(|(Width(w), _)| w as usize)
+        .unwrap_or(DEFAULT_TERM_WIDTH)
+}
+
+/// Spawns a thread that watches for terminal resizes and sends an [Event::Resize] when one happens
+#[cfg(unix)]
+fn spawn_resize_listener(
+    writer: Writer,
+    stop: Arc<AtomicBool>,
+) -> (JoinHandle<()>, signal_hook::iterator::Handle) {
+    use signal_hook::{consts::SIGWINCH, iterator::Signals};
+
+    let mut signals = Signals::new([SIGWINCH]).expect("Failed to register SIGWINCH handler");
+    let handle = signals.handle();
+
+    let join_handle = Builder::new()
+        .name("CLIDisplayManagerResizeThread".to_string())
+        .spawn(move || {
+            for _ in signals.forever() {
+                if stop.load(Relaxed) {
+                    break;
+                }
+
+                writer.send(Event::Resize(query_terminal_width()));
+            }
+        })
+        .unwrap();
+
+    (join_handle, handle)
+}
+
+/// Spawns a thread that watches for terminal resizes and sends an [Event::Resize] when one happens
+#[cfg(not(unix))]
+fn spawn_resize_listener(writer: Writer, stop: Arc<AtomicBool>) -> JoinHandle<()> {
+    Builder::new()
+        .name("CLIDisplayManagerResizeThread".to_string())
+        .spawn(move || {
+            let mut last_width = query_terminal_width();
+
+            while !stop.load(Relaxed) {
+                let width = query_terminal_width();
+
+                if width != last_width {
+                    last_width = width;
+                    writer.send(Event::Resize(width));
+                }
+
+                std::thread::sleep(Duration::from_millis(250));
+            }
+        })
+        .unwrap()
+}
 
 struct CursorHideGuard;
 
@@ -58,16 +119,193 @@ impl Drop for CursorHideGuard {
     }
 }
 
+/// A leaky-bucket gate that caps how often draws are allowed to happen.
+///
+/// Each allowed draw adds `1.0` of work, which leaks away over time at `leak_rate` per second.
+/// A draw is only allowed while the accumulated work is below the bucket's capacity of `1.0`.
+struct DrawThrottle {
+    last_update: Instant,
+    leak_rate: f64,
+    accumulated: f64,
+}
+
+impl DrawThrottle {
+    fn new(leak_rate: f64) -> Self {
+        Self {
+            last_update: Instant::now(),
+            leak_rate,
+            accumulated: 0.0,
+        }
+    }
+
+    /// Attempts to register a draw, returning whether it is allowed to proceed.
+    fn try_add_work(&mut self) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_update);
+
+        self.accumulated = (self.accumulated - elapsed.as_secs_f64() * self.leak_rate).max(0.0);
+        self.last_update = now;
+
+        if self.accumulated < 1.0 {
+            self.accumulated += 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Draws allowed per second by the render thread's [DrawThrottle]
+const DEFAULT_DRAW_RATE: f64 = 20.0;
+
+/// An operation applied to the display tree by the render thread, in the order it was sent.
+///
+/// Sent through a [Writer], either directly or batched by [CLIDisplayManager::modify].
+pub enum Event {
+    /// Adds another parallel task or subtask if only the root node is present
+    Push(CLIDisplayNodeType),
+    /// Removes the last displayed item
+    Pop,
+    /// Makes a new subtask for the current task
+    MakeSub(CLIDisplayNodeType),
+    /// Replaces the root node with a different one
+    ReplaceRoot(CLIDisplayNodeType),
+    /// Sets the last node's progress, if it is a [ProgressBar](CLIDisplayNodeType::ProgressBar)
+    SetProgress(u8),
+    /// Prints a line to stdout without interrupting the display
+    PrintLine(Cow<'static, str>),
+    /// Sets the maximum number of draws per second the render thread is allowed to perform.
+    ///
+    /// This does not limit draws caused by structural changes (push/pop/`make_sub`/`replace_root`/resize),
+    /// which always force a draw.
+    SetDrawRate(f64),
+    /// Advances the spinner/animation frame; sent by the render thread's own tick timer
+    Tick,
+    /// Updates the cached terminal width
+    Resize(usize),
+    /// Stops the render thread
+    Stop,
+}
+
+/// A cloneable handle that sends [Event]s to a [CLIDisplayManager]'s render thread.
+///
+/// Sending through a [Writer] never blocks on stdout or holds a lock, and unlike
+/// [modify](CLIDisplayManager::modify) it needs no `&mut` access to the manager, so it can be used
+/// from worker threads or async tasks that already have their own event loop.
+#[derive(Clone)]
+pub struct Writer {
+    sender: Sender<Event>,
+}
+
+impl Writer {
+    /// Queues `event` for the render thread to apply, in order, before its next redraw
+    pub fn send(&self, event: Event) {
+        let _ = self.sender.send(event);
+    }
+
+    /// Adds another parallel task or subtask if only the root node is present
+    pub fn push(&self, node_type: CLIDisplayNodeType) {
+        self.send(Event::Push(node_type));
+    }
+
+    /// Removes the last displayed item
+    pub fn pop(&self) {
+        self.send(Event::Pop);
+    }
+
+    /// Makes a new subtask for the current task
+    pub fn make_sub(&self, node_type: CLIDisplayNodeType) {
+        self.send(Event::MakeSub(node_type));
+    }
+
+    /// Replaces the root node with a different one
+    pub fn replace_root(&self, node_type: CLIDisplayNodeType) {
+        self.send(Event::ReplaceRoot(node_type));
+    }
+
+    /// Sets the last node's progress, if it is a [ProgressBar](CLIDisplayNodeType::ProgressBar)
+    pub fn set_progress(&self, value: u8) {
+        self.send(Event::SetProgress(value));
+    }
+
+    /// Prints a line to stdout without interrupting the display
+    pub fn print_line(&self, line: impl Into<Cow<'static, str>>) {
+        self.send(Event::PrintLine(line.into()));
+    }
+
+    /// Sets the maximum number of draws per second the render thread is allowed to perform.
+    ///
+    /// This does not limit draws caused by structural changes (push/pop/`make_sub`/`replace_root`/resize),
+    /// which always force a draw.
+    pub fn set_draw_rate(&self, rate: f64) {
+        self.send(Event::SetDrawRate(rate));
+    }
+}
+
+/// Applies a [Push](Event::Push) event to `root`, falling back to [apply_make_sub] if it has no sub-nodes yet
+fn apply_push(root: &mut CLIDisplayNode, node_type: CLIDisplayNodeType) {
+    if root.sub_nodes.is_empty() {
+        return apply_make_sub(root, node_type);
+    }
+
+    let mut mapped_node = root;
+
+    while mapped_node.sub_nodes.last().unwrap().sub_nodes.len() != 0 {
+        mapped_node = mapped_node.sub_nodes.last_mut().unwrap();
+    }
+
+    mapped_node.sub_nodes.push(CLIDisplayNode::new(node_type));
+}
+
+/// Applies a [MakeSub](Event::MakeSub) event to `root`
+fn apply_make_sub(root: &mut CLIDisplayNode, node_type: CLIDisplayNodeType) {
+    let mut last_node = root;
+
+    while last_node.sub_nodes.len() != 0 {
+        last_node = last_node.sub_nodes.last_mut().unwrap();
+    }
+
+    last_node.sub_nodes.push(CLIDisplayNode::new(node_type));
+}
+
+/// Applies a [Pop](Event::Pop) event to `root`
+fn apply_pop(root: &mut CLIDisplayNode) {
+    if root.sub_nodes.is_empty() {
+        return;
+    }
+
+    let mut mapped_node = root;
+
+    while mapped_node.sub_nodes.last().unwrap().sub_nodes.len() != 0 {
+        mapped_node = mapped_node.sub_nodes.last_mut().unwrap();
+    }
+
+    forget(mapped_node.sub_nodes.pop());
+}
+
+/// Applies a [SetProgress](Event::SetProgress) event to `root`'s last node
+fn apply_set_progress(root: &mut CLIDisplayNode, value: u8) {
+    let mut node = root;
+
+    while node.sub_nodes.len() != 0 {
+        node = node.sub_nodes.last_mut().unwrap();
+    }
+
+    if let CLIDisplayNodeType::ProgressBar(progress, _) = &node.node_type {
+        progress.store(value.min(100), Relaxed);
+    }
+}
+
 /// This is the core struct of the library.
 /// Everything is managed here.
 /// Create this with the initial root item and a refresh rate and drop it when done.
 pub struct CLIDisplayManager {
-    root: Arc<RwLock<CLIDisplayNode>>,
-    cv: Arc<Condvar>,
-    mutex: Arc<Mutex<()>>,
+    writer: Writer,
     self_handle: Option<JoinHandle<()>>,
     stop: Arc<AtomicBool>,
-    tick_counter: Arc<AtomicUsize>,
+    resize_handle: Option<JoinHandle<()>>,
+    #[cfg(unix)]
+    resize_signal_handle: Option<signal_hook::iterator::Handle>,
     _cursor_visibility_guard: CursorHideGuard,
 }
 
@@ -76,196 +314,194 @@ impl CLIDisplayManager {
     pub fn new(root_node: CLIDisplayNodeType, tick_rate: u32) -> Self {
         let _ = enable_ansi_support::enable_ansi_support();
 
-        let mut clidm = Self {
-            root: RwLock::new(CLIDisplayNode::new(root_node)).into(),
-            cv: Condvar::new().into(),
-            mutex: Mutex::new(()).into(),
-            self_handle: None,
-            stop: AtomicBool::new(false).into(),
-            tick_counter: AtomicUsize::new(0).into(),
-            _cursor_visibility_guard: CursorHideGuard::new(),
-        };
+        let (sender, receiver) = mpsc::channel();
+        let writer = Writer { sender };
+        let stop: Arc<AtomicBool> = AtomicBool::new(false).into();
+
+        let self_handle = Builder::new()
+            .name("CLIDisplayManagerThread".to_string())
+            .spawn(move || {
+                let mut root = CLIDisplayNode::new(root_node);
+                let mut tick_counter: usize = 0;
+                let mut term_width = query_terminal_width();
+                let mut draw_throttle = DrawThrottle::new(DEFAULT_DRAW_RATE);
+
+                let mut previous_lines = root.display(0, tick_counter, true, term_width);
+                root.go_back(previous_lines);
+                print!("\r");
+                let _ = stdout().flush();
+
+                loop {
+                    let event = if tick_rate != 0 {
+                        match receiver.recv_timeout(Duration::from_secs(1) / tick_rate) {
+                            Ok(event) => event,
+                            Err(mpsc::RecvTimeoutError::Timeout) => Event::Tick,
+                            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                        }
+                    } else {
+                        match receiver.recv() {
+                            Ok(event) => event,
+                            Err(_) => break,
+                        }
+                    };
 
-        let stop = clidm.stop.clone();
-        let cv = clidm.cv.clone();
-        let mutex = clidm.mutex.clone();
-        let root = clidm.root.clone();
-        let tick_counter = clidm.tick_counter.clone();
-
-        clidm.self_handle.replace(
-            Builder::new()
-                .name("CLIDisplayManagerThread".to_string())
-                .spawn(move || {
-                    let mut guard = mutex
-                        .lock()
-                        .expect("Poisoned mutex in CLIDisplayManagerThread!!!");
-
-                    let node = root
-                        .read()
-                        .expect("Poisoned rwlock in CLIDisplayManagerThread!!!");
-                    node.display(0, tick_counter.load(Relaxed), true);
-                    node.go_back();
-                    drop(node);
-
-                    while !stop.load(Relaxed) {
-                        let node = root
-                            .read()
-                            .expect("Poisoned rwlock in CLIDisplayManagerThread!!!");
-                        node.display(0, tick_counter.load(Relaxed), true);
-                        node.go_back();
-                        drop(node);
-                        print!("\r");
+                    let mut force = false;
 
-                        tick_counter.fetch_add(1, Relaxed);
-                        if tick_rate != 0 {
-                            guard = cv
-                                .wait_timeout(guard, Duration::from_secs(1) / tick_rate)
-                                .expect("Poisoned condition variable in CLIDisplayManagerThread!!!")
-                                .0;
-                        } else {
-                            guard = cv.wait(guard).expect(
-                                "Poisoned condition variable in CLIDisplayManagerThread!!!",
-                            );
+                    match event {
+                        Event::Push(node_type) => {
+                            apply_push(&mut root, node_type);
+                            force = true;
+                        }
+                        Event::Pop => {
+                            apply_pop(&mut root);
+                            force = true;
+                        }
+                        Event::MakeSub(node_type) => {
+                            apply_make_sub(&mut root, node_type);
+                            force = true;
+                        }
+                        Event::ReplaceRoot(node_type) => {
+                            root.node_type = node_type;
+                            force = true;
+                        }
+                        Event::SetProgress(value) => {
+                            apply_set_progress(&mut root, value);
+                        }
+                        Event::PrintLine(line) => {
+                            println!("{}{}", ERASE_LINE, line);
+                        }
+                        Event::SetDrawRate(rate) => {
+                            draw_throttle.leak_rate = rate;
+                        }
+                        Event::Resize(width) => {
+                            term_width = width;
+                            force = true;
+                        }
+                        Event::Tick => {
+                            tick_counter = tick_counter.wrapping_add(1);
                         }
+                        Event::Stop => break,
                     }
-                })
-                .unwrap(),
-        );
 
-        clidm
-    }
+                    let should_draw = force || draw_throttle.try_add_work();
 
-    /// Modifies a [CLIDisplayManager]s output through a [CLIModificationElement] handle that gets passed to a callback
-    pub fn modify<F: FnOnce(&mut CLIModificationElement) -> ()>(&mut self, f: F) {
-        let guard = self.mutex.lock();
+                    if should_draw {
+                        let lines = root.display(0, tick_counter, true, term_width);
+                        let removed_lines = previous_lines.saturating_sub(lines);
 
-        let mut modification_element = CLIModificationElement {
-            root_node: &self.root,
-            additions: 0,
-        };
+                        for i in 1..=removed_lines {
+                            print!("{}", ERASE_LINE);
 
-        f(&mut modification_element);
+                            if i != removed_lines {
+                                println!("");
+                            }
+                        }
 
-        let removed_lines = modification_element.additions.neg().max(0);
+                        for _ in 1..removed_lines {
+                            print!("{}", CURSOR_UP);
+                        }
 
-        drop(modification_element);
+                        root.go_back(lines);
+                        print!("\r");
+                        let _ = stdout().flush();
 
-        let node = self
-            .root
-            .read()
-            .expect("Poisoned rwlock in CLIDisplayManagerThread!!!");
-        node.display(0, self.tick_counter.load(Relaxed), true);
+                        previous_lines = lines;
+                    }
+                }
+            })
+            .unwrap();
 
-        for i in 1..=removed_lines {
-            print!("{}", ERASE_LINE);
+        #[cfg(unix)]
+        let (resize_handle, resize_signal_handle) =
+            spawn_resize_listener(writer.clone(), stop.clone());
 
-            if i != removed_lines {
-                println!("");
-            }
+        #[cfg(not(unix))]
+        let resize_handle = spawn_resize_listener(writer.clone(), stop.clone());
+
+        Self {
+            writer,
+            self_handle: Some(self_handle),
+            stop,
+            resize_handle: Some(resize_handle),
+            #[cfg(unix)]
+            resize_signal_handle: Some(resize_signal_handle),
+            _cursor_visibility_guard: CursorHideGuard::new(),
         }
+    }
 
-        for _ in 1..removed_lines {
-            print!("{}", CURSOR_UP);
+    /// Modifies a [CLIDisplayManager]s output through a [CLIModificationElement] handle that gets passed to a callback.
+    ///
+    /// This is a convenience wrapper that batches the events recorded by `f` and sends them, in order,
+    /// through this manager's [Writer].
+    pub fn modify<F: FnOnce(&mut CLIModificationElement) -> ()>(&mut self, f: F) {
+        let mut modification_element = CLIModificationElement { events: Vec::new() };
+
+        f(&mut modification_element);
+
+        for event in modification_element.events {
+            self.writer.send(event);
         }
+    }
 
-        node.go_back();
-        drop(node);
-        print!("\r");
-        let _ = stdout().flush();
+    /// Returns a cloneable [Writer] that can send [Event]s to this manager's render thread from any thread
+    pub fn writer(&self) -> Writer {
+        self.writer.clone()
+    }
 
-        drop(guard);
+    /// Sets the maximum number of draws per second the render thread is allowed to perform.
+    ///
+    /// This does not limit draws caused by structural changes (push/pop/`make_sub`/`replace_root`/resize), which always force a draw.
+    pub fn set_draw_rate(&self, rate: f64) {
+        self.writer.set_draw_rate(rate);
     }
 }
 
 impl Drop for CLIDisplayManager {
     fn drop(&mut self) {
+        self.writer.send(Event::Stop);
         self.stop.store(true, Relaxed);
 
-        self.cv.notify_all();
+        #[cfg(unix)]
+        if let Some(resize_signal_handle) = self.resize_signal_handle.take() {
+            resize_signal_handle.close();
+        }
 
         self.self_handle.take().unwrap().join().unwrap();
+        self.resize_handle.take().unwrap().join().unwrap();
     }
 }
 
 /// This is the struct through which the output of a [CLIDisplayManager] can be changed.
-pub struct CLIModificationElement<'a> {
-    root_node: &'a RwLock<CLIDisplayNode>,
-    additions: isize,
+///
+/// Methods record [Event]s that [CLIDisplayManager::modify] sends to the render thread once the callback returns.
+pub struct CLIModificationElement {
+    events: Vec<Event>,
 }
 
-impl<'a> CLIModificationElement<'a> {
+impl CLIModificationElement {
     /// Removes the last displayed item
     pub fn pop(&mut self) {
-        self.additions -= 1;
-
-        let mut node = self
-            .root_node
-            .write()
-            .expect("Poisoned rwlock in CLIModificationElement!!!");
-
-        if node.sub_nodes.len() == 0 {
-            self.additions += 1;
-            return;
-        }
-
-        let mut mapped_node = &mut *node;
-
-        while mapped_node.sub_nodes.last().unwrap().sub_nodes.len() != 0 {
-            mapped_node = mapped_node.sub_nodes.last_mut().unwrap();
-        }
-
-        forget(mapped_node.sub_nodes.pop());
+        self.events.push(Event::Pop);
     }
 
     /// Adds another parallel task or subtask if only the root node is present
     pub fn push(&mut self, node_type: CLIDisplayNodeType) {
-        self.additions += 1;
-
-        let mut node = self
-            .root_node
-            .write()
-            .expect("Poisoned rwlock in CLIModificationElement!!!");
-
-        if node.sub_nodes.len() == 0 {
-            drop(node);
-
-            self.additions -= 1;
-            return Self::make_sub(self, node_type);
-        }
-
-        let mut mapped_node = &mut *node;
-
-        while mapped_node.sub_nodes.last().unwrap().sub_nodes.len() != 0 {
-            mapped_node = mapped_node.sub_nodes.last_mut().unwrap();
-        }
-
-        mapped_node.sub_nodes.push(CLIDisplayNode::new(node_type));
+        self.events.push(Event::Push(node_type));
     }
 
     /// Makes a new subtask for the current task
     pub fn make_sub(&mut self, node_type: CLIDisplayNodeType) {
-        self.additions += 1;
-
-        let mut node = self
-            .root_node
-            .write()
-            .expect("Poisoned rwlock in CLIModificationElement!!!");
-
-        let mut last_node = &mut *node;
-
-        while last_node.sub_nodes.len() != 0 {
-            last_node = last_node.sub_nodes.last_mut().unwrap();
-        }
-
-        last_node.sub_nodes.push(CLIDisplayNode::new(node_type));
+        self.events.push(Event::MakeSub(node_type));
     }
 
     /// Replaces the root node with a different one
     pub fn replace_root(&mut self, node_type: CLIDisplayNodeType) {
-        self.root_node
-            .write()
-            .expect("Poisoned rwlock in CLIModificationElement!!!")
-            .node_type = node_type;
+        self.events.push(Event::ReplaceRoot(node_type));
+    }
+
+    #[doc(hidden)]
+    pub fn _print_line(&mut self, line: impl Into<Cow<'static, str>>) {
+        self.events.push(Event::PrintLine(line.into()));
     }
 }
 
@@ -282,11 +518,14 @@ impl CLIDisplayNode {
         }
     }
 
-    fn display(&self, depth: usize, tick_counter: usize, last: bool) {
+    /// Draws this node and its sub-nodes, returning the number of physical terminal lines printed
+    fn display(&self, depth: usize, tick_counter: usize, last: bool, width: usize) -> usize {
         print!("{}", ERASE_LINE);
+        let mut indent_width = 0;
         if depth != 0 {
             for _ in 1..depth {
                 print!("  ");
+                indent_width += 2;
             }
 
             if last {
@@ -294,21 +533,24 @@ impl CLIDisplayNode {
             } else {
                 print!("\u{251C}\u{2574}");
             }
+            indent_width += 2;
         }
 
-        self.node_type.display(tick_counter);
+        let available_width = width.saturating_sub(indent_width);
+        let mut lines = self.node_type.display(tick_counter, available_width);
 
         for (index, sub_node) in self.sub_nodes.iter().enumerate() {
-            sub_node.display(depth + 1, tick_counter, index + 1 == self.sub_nodes.len());
+            lines += sub_node.display(depth + 1, tick_counter, index + 1 == self.sub_nodes.len(), width);
         }
+
+        lines
     }
 
-    fn go_back(&self) {
-        for sub_node in self.sub_nodes.iter() {
-            sub_node.go_back();
+    /// Rewinds the cursor by the given number of physical terminal lines
+    fn go_back(&self, lines: usize) {
+        for _ in 0..lines {
+            print!("{}", CURSOR_UP);
         }
-
-        print!("{}", CURSOR_UP);
     }
 }
 
@@ -324,50 +566,317 @@ pub enum CLIDisplayNodeType {
     Message(Cow<'static, str>),
     /// Text with an animated spinner at the end
     SpinningMessage(Cow<'static, str>),
-    /// A controllable progress bar
-    ProgressBar(Arc<AtomicU8>),
+    /// A controllable progress bar, rendered in the given [ProgressBarStyle]
+    ProgressBar(Arc<AtomicU8>, ProgressBarStyle),
+    /// A transfer-style node showing position/total, a smoothed throughput and an ETA
+    RateProgress(Arc<RateProgressState>),
+}
+
+/// Smoothing state for a [RateProgress](CLIDisplayNodeType::RateProgress) node's throughput estimate
+struct RateSmoothing {
+    last_pos: usize,
+    last_instant: Instant,
+    ema: f64,
+}
+
+/// How strongly each new rate sample pulls the smoothed estimate, in `[0, 1]`
+const RATE_EMA_ALPHA: f64 = 0.1;
+
+/// Shared state behind a [RateProgress](CLIDisplayNodeType::RateProgress) node
+pub struct RateProgressState {
+    /// The current position, e.g. bytes transferred so far
+    pub position: Arc<AtomicUsize>,
+    total: usize,
+    smoothing: Mutex<RateSmoothing>,
+}
+
+impl RateProgressState {
+    /// Creates tracking state for a transfer of `total` units, starting at position `0`
+    pub fn new(total: usize) -> Arc<Self> {
+        Arc::new(Self {
+            position: AtomicUsize::new(0).into(),
+            total,
+            smoothing: Mutex::new(RateSmoothing {
+                last_pos: 0,
+                last_instant: Instant::now(),
+                ema: 0.0,
+            }),
+        })
+    }
+}
+
+/// Formats `bytes` using binary (KiB/MiB/GiB) units
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{value:.0} {}", UNITS[unit])
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Formats a duration in seconds as `h:mm:ss`, or `--:--:--` if it can't be estimated yet
+fn format_eta(seconds: f64) -> String {
+    if !seconds.is_finite() || seconds < 0.0 {
+        return "--:--:--".to_string();
+    }
+
+    let total_seconds = seconds.round() as u64;
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let secs = total_seconds % 60;
+
+    format!("{hours}:{minutes:02}:{secs:02}")
+}
+
+/// The rendering resolution used for a [ProgressBar](CLIDisplayNodeType::ProgressBar)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressBarStyle {
+    /// Fills one `#` per cell, so each step is `100 / bar width` percent. Works in any terminal.
+    #[default]
+    Coarse,
+    /// Uses the Unicode eighth-block characters (`▏▎▍▌▋▊▉█`) for 1/8-cell resolution.
+    /// Requires a UTF-8-capable terminal font.
+    Fine,
+}
+
+/// The Unicode partial-block characters used by [ProgressBarStyle::Fine], from thinnest to a full block
+const EIGHTH_BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// Renders a fine-grained bar of `width` cells at fractional fill `fraction` (in `[0, 1]`)
+fn render_fine_bar(fraction: f64, width: usize) -> String {
+    let scaled = fraction.clamp(0.0, 1.0) * width as f64;
+    let mut full = (scaled.floor() as usize).min(width);
+    let remainder = scaled - full as f64;
+
+    let mut index = (remainder * 8.0).round() as usize;
+    if index >= 8 {
+        full = (full + 1).min(width);
+        index = 0;
+    }
+
+    let mut bar = String::with_capacity(width);
+    for _ in 0..full {
+        bar.push('█');
+    }
+
+    if full < width {
+        bar.push(if index == 0 {
+            ' '
+        } else {
+            EIGHTH_BLOCKS[index - 1]
+        });
+
+        for _ in (full + 1)..width {
+            bar.push(' ');
+        }
+    }
+
+    bar
+}
+
+/// Truncates `s` to at most `width` characters, marking truncation with a trailing `…`
+fn truncate_to_width(s: &str, width: usize) -> Cow<'_, str> {
+    if s.chars().count() <= width {
+        return Cow::Borrowed(s);
+    }
+
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+
+    Cow::Owned(s.chars().take(width.saturating_sub(1)).chain(['…']).collect())
+}
+
+/// Picks a progress bar width that fits within the available space.
+///
+/// Prefers [MIN_BAR_WIDTH]..=[MAX_BAR_WIDTH], but degrades below [MIN_BAR_WIDTH] rather than
+/// overflowing the line when the terminal is narrower than that.
+fn bar_width_for(available_width: usize) -> usize {
+    let space = available_width.saturating_sub(2);
+    space.clamp(MIN_BAR_WIDTH, MAX_BAR_WIDTH).min(space)
 }
 
 impl CLIDisplayNodeType {
-    fn display(&self, tick_counter: usize) {
+    /// Draws this node's contents, returning the number of physical terminal lines printed
+    fn display(&self, tick_counter: usize, available_width: usize) -> usize {
         match self {
-            CLIDisplayNodeType::Message(cow) => println!("{}", cow),
+            CLIDisplayNodeType::Message(cow) => {
+                println!("{}", truncate_to_width(cow, available_width))
+            }
             CLIDisplayNodeType::SpinningMessage(cow) => {
-                println!("{} {}", cow, "/-\\|".chars().nth(tick_counter % 4).unwrap())
+                let spinner = "/-\\|".chars().nth(tick_counter % 4).unwrap();
+                let line = format!("{} {}", cow, spinner);
+                println!("{}", truncate_to_width(&line, available_width));
             }
-            CLIDisplayNodeType::ProgressBar(progress) => {
-                let mut lock = stdout().lock();
-                let progress = (progress.load(Relaxed) / 5).clamp(0, 20);
+            CLIDisplayNodeType::ProgressBar(progress, ProgressBarStyle::Coarse) => {
+                let bar_width = bar_width_for(available_width);
+                let filled = ((progress.load(Relaxed) as usize * bar_width) / 100).clamp(0, bar_width);
 
-                let _ = write!(lock, "[");
+                let mut bar = String::with_capacity(bar_width + 2);
+                bar.push('[');
 
-                for _ in 0..progress {
-                    let _ = write!(lock, "#");
+                for _ in 0..filled {
+                    bar.push('#');
                 }
 
-                if progress != 20 {
-                    let _ = write!(lock, "{}", "/-\\|".chars().nth(tick_counter % 4).unwrap());
+                if filled != bar_width {
+                    bar.push("/-\\|".chars().nth(tick_counter % 4).unwrap());
                 }
 
-                for _ in progress..19 {
-                    let _ = write!(lock, " ");
+                for _ in filled..bar_width.saturating_sub(1) {
+                    bar.push(' ');
                 }
 
-                let _ = writeln!(lock, "]");
+                bar.push(']');
+
+                println!("{}", truncate_to_width(&bar, available_width));
+            }
+            CLIDisplayNodeType::ProgressBar(progress, ProgressBarStyle::Fine) => {
+                let bar_width = bar_width_for(available_width);
+                let fraction = progress.load(Relaxed) as f64 / 100.0;
+                let bar = format!("[{}]", render_fine_bar(fraction, bar_width));
+
+                println!("{}", truncate_to_width(&bar, available_width));
+            }
+            CLIDisplayNodeType::RateProgress(state) => {
+                let pos = state.position.load(Relaxed).min(state.total);
+                let now = Instant::now();
+
+                let ema = {
+                    let mut smoothing = state
+                        .smoothing
+                        .lock()
+                        .expect("Poisoned mutex in CLIDisplayNodeType!!!");
+
+                    let elapsed = now.duration_since(smoothing.last_instant).as_secs_f64();
+                    if elapsed > 0.0 {
+                        let rate = pos.saturating_sub(smoothing.last_pos) as f64 / elapsed;
+                        smoothing.ema = smoothing.ema * (1.0 - RATE_EMA_ALPHA) + rate * RATE_EMA_ALPHA;
+                        smoothing.last_pos = pos;
+                        smoothing.last_instant = now;
+                    }
+
+                    smoothing.ema
+                };
+
+                let remaining = state.total.saturating_sub(pos) as f64;
+                let eta = if ema > 0.0 {
+                    remaining / ema
+                } else {
+                    f64::INFINITY
+                };
+
+                let line = format!(
+                    "{} / {}  {}/s  ETA {}",
+                    format_bytes(pos as f64),
+                    format_bytes(state.total as f64),
+                    format_bytes(ema),
+                    format_eta(eta)
+                );
+
+                println!("{}", truncate_to_width(&line, available_width));
             }
         }
+
+        1
     }
 }
 
+/// An iterator adapter that advances a shared [ProgressBar](CLIDisplayNodeType::ProgressBar) as it's consumed.
+///
+/// Created through [ProgressIterator::progress_with] or [ProgressIterator::progress_count].
+pub struct Progress<I> {
+    iter: I,
+    bar: Arc<AtomicU8>,
+    len: Option<usize>,
+    consumed: usize,
+    spin: u8,
+}
+
+impl<I: Iterator> Iterator for Progress<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+
+        if item.is_some() {
+            self.consumed += 1;
+
+            let percent = match self.len {
+                Some(0) => 100,
+                Some(len) => ((self.consumed * 100) / len).clamp(0, 100) as u8,
+                None => {
+                    self.spin = self.spin.wrapping_add(5) % 100;
+                    self.spin
+                }
+            };
+
+            self.bar.store(percent, Relaxed);
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Extension trait adding progress-reporting adapters to any [Iterator]
+pub trait ProgressIterator: Iterator + Sized {
+    /// Wraps this iterator so each call to `next` advances `bar` based on items consumed versus
+    /// the iterator's [ExactSizeIterator] length.
+    ///
+    /// If no usable length is available, `bar` is advanced as a spinner-style counter instead of a percentage.
+    fn progress_with(self, bar: Arc<AtomicU8>) -> Progress<Self> {
+        let len = match self.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(upper),
+            _ => None,
+        };
+
+        Progress {
+            iter: self,
+            bar,
+            len,
+            consumed: 0,
+            spin: 0,
+        }
+    }
+
+    /// Like [progress_with](ProgressIterator::progress_with), but with an explicit known length
+    /// instead of relying on the iterator's [ExactSizeIterator] length
+    fn progress_count(self, bar: Arc<AtomicU8>, len: usize) -> Progress<Self> {
+        Progress {
+            iter: self,
+            bar,
+            len: Some(len),
+            consumed: 0,
+            spin: 0,
+        }
+    }
+}
+
+impl<I: Iterator> ProgressIterator for I {}
+
 /// This macro can be used in modify calls to add lines to stdout without interrupting the [CLIDisplayManager]
 #[macro_export]
 macro_rules! erasing_println {
     ($me:ident) => {{
-        let _: &mut $crate::CLIModificationElement = $me;
-        print!("{}\n", $crate::_ERASE_LINE)
+        let me: &mut $crate::CLIModificationElement = $me;
+        me._print_line(String::new())
     }};
     ($me:ident, $($arg:tt)*) => {{
-        let _: &mut $crate::CLIModificationElement = $me;
-        print!("{}{}\n", $crate::_ERASE_LINE, format_args!($($arg)*));
+        let me: &mut $crate::CLIModificationElement = $me;
+        me._print_line(format!($($arg)*))
     }};
 }